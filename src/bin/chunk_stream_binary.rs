@@ -0,0 +1,14 @@
+use std::io::{self, Write};
+use stdin_nonblocking::spawn_stdin_stream_with_capacity;
+
+// Used for integration testing incremental delivery: echoes each chunk back
+// to stdout (flushed) as soon as it's received, instead of buffering to EOF.
+fn main() {
+    let stream = spawn_stdin_stream_with_capacity(4);
+    let mut stdout = io::stdout();
+
+    while let Ok(chunk) = stream.recv() {
+        stdout.write_all(&chunk).expect("Failed to write output");
+        stdout.flush().expect("Failed to flush output");
+    }
+}
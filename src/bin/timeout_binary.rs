@@ -0,0 +1,14 @@
+use std::io::{self, Write};
+use std::time::Duration;
+use stdin_nonblocking::get_stdin_or_default_timeout;
+
+// Used for integration testing the fallback timeout: a pipe that opens but
+// never sends data should still fall back after `timeout`, rather than
+// blocking forever on a full `recv()`.
+fn main() {
+    let input = get_stdin_or_default_timeout(Some(b"fallback_value"), Duration::from_millis(100));
+
+    io::stdout()
+        .write_all(&input.unwrap_or_default())
+        .expect("Failed to write output");
+}
@@ -0,0 +1,208 @@
+//! Frame decoding on top of the raw chunked stdin stream.
+//!
+//! Consumers that need framed messages (lines, length-prefixed records, ...)
+//! instead of raw byte chunks can implement [`Decoder`] and drive it with
+//! [`spawn_stdin_frames`], which takes care of buffering partial frames across
+//! reads.
+
+use crate::spawn_stdin_stream;
+use std::sync::mpsc::Receiver;
+
+/// Decodes a stream of bytes into discrete frames.
+///
+/// Implementations are handed a growable buffer of not-yet-decoded bytes and
+/// are called repeatedly as more data arrives. A single call to `decode` may
+/// produce zero or one frame; `spawn_stdin_frames` calls it in a loop so that
+/// multiple complete frames in `buf` are all drained before more bytes are
+/// read.
+pub trait Decoder {
+    /// Attempts to decode a frame from `buf`.
+    ///
+    /// Returns `Some(frame)` and removes the consumed bytes from `buf` when a
+    /// complete frame is available. Returns `None` if `buf` doesn't yet
+    /// contain a full frame; the bytes are left in place for the next call.
+    fn decode(&mut self, buf: &mut Vec<u8>) -> Option<Vec<u8>>;
+
+    /// Called once after the underlying stream has reached EOF, to flush any
+    /// data left in `buf` that didn't end in a natural frame boundary.
+    ///
+    /// The default implementation returns `None`, discarding leftover bytes.
+    fn decode_eof(&mut self, _buf: &mut Vec<u8>) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// Splits incoming bytes into lines on `\n`, stripping an optional trailing `\r`.
+///
+/// A final line with no trailing newline is flushed on EOF instead of being
+/// discarded.
+#[derive(Debug, Default)]
+pub struct LinesDecoder;
+
+impl Decoder for LinesDecoder {
+    fn decode(&mut self, buf: &mut Vec<u8>) -> Option<Vec<u8>> {
+        let pos = buf.iter().position(|&b| b == b'\n')?;
+        let mut line: Vec<u8> = buf.drain(..=pos).collect();
+        line.pop(); // Drop the `\n`
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Some(line)
+    }
+
+    fn decode_eof(&mut self, buf: &mut Vec<u8>) -> Option<Vec<u8>> {
+        if buf.is_empty() {
+            return None;
+        }
+        let mut line = std::mem::take(buf);
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+        Some(line)
+    }
+}
+
+/// Reads a 4-byte big-endian length prefix followed by that many payload bytes.
+///
+/// The emitted frame is the payload only (the length prefix is stripped).
+/// Partial prefixes and partial payloads are buffered across reads until the
+/// full frame is present.
+#[derive(Debug, Default)]
+pub struct LengthDelimitedDecoder;
+
+const LENGTH_PREFIX_BYTES: usize = 4;
+
+impl Decoder for LengthDelimitedDecoder {
+    fn decode(&mut self, buf: &mut Vec<u8>) -> Option<Vec<u8>> {
+        if buf.len() < LENGTH_PREFIX_BYTES {
+            return None;
+        }
+
+        let len = u32::from_be_bytes(buf[..LENGTH_PREFIX_BYTES].try_into().unwrap()) as usize;
+        if buf.len() < LENGTH_PREFIX_BYTES + len {
+            return None;
+        }
+
+        buf.drain(..LENGTH_PREFIX_BYTES);
+        Some(buf.drain(..len).collect())
+    }
+}
+
+/// Spawns a background thread that reads stdin and emits decoded frames.
+///
+/// This builds on the same chunked stdin reader as [`spawn_stdin_stream`],
+/// maintaining an internal buffer that incoming bytes are appended to. After
+/// each chunk, `decoder.decode` is called repeatedly so every complete frame
+/// already in the buffer is emitted before more bytes are read. Once stdin
+/// reaches EOF, `decoder.decode_eof` is given one final chance to flush a
+/// trailing partial frame.
+///
+/// # Example
+/// ```no_run
+/// use stdin_nonblocking::decoder::{spawn_stdin_frames, LinesDecoder};
+///
+/// let lines = spawn_stdin_frames(LinesDecoder);
+/// while let Ok(line) = lines.recv() {
+///     println!("{:?}", line);
+/// }
+/// ```
+pub fn spawn_stdin_frames<D>(mut decoder: D) -> Receiver<Vec<u8>>
+where
+    D: Decoder + Send + 'static,
+{
+    let (tx, rx) = std::sync::mpsc::channel();
+    let chunks = spawn_stdin_stream();
+
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+
+        while let Ok(chunk) = chunks.recv() {
+            buf.extend_from_slice(&chunk);
+
+            while let Some(frame) = decoder.decode(&mut buf) {
+                if tx.send(frame).is_err() {
+                    return;
+                }
+            }
+        }
+
+        if let Some(frame) = decoder.decode_eof(&mut buf) {
+            let _ = tx.send(frame);
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_decoder_buffers_a_frame_split_across_calls() {
+        let mut decoder = LinesDecoder;
+        let mut buf = b"hel".to_vec();
+
+        // No newline yet: nothing to emit, and the partial bytes stay buffered.
+        assert_eq!(decoder.decode(&mut buf), None);
+        assert_eq!(buf, b"hel");
+
+        buf.extend_from_slice(b"lo\nworld");
+        assert_eq!(decoder.decode(&mut buf), Some(b"hello".to_vec()));
+        // The second, still-incomplete line is left in the buffer.
+        assert_eq!(buf, b"world");
+    }
+
+    #[test]
+    fn lines_decoder_strips_trailing_cr() {
+        let mut decoder = LinesDecoder;
+        let mut buf = b"hello\r\n".to_vec();
+
+        assert_eq!(decoder.decode(&mut buf), Some(b"hello".to_vec()));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn lines_decoder_flushes_trailing_unterminated_line_on_eof() {
+        let mut decoder = LinesDecoder;
+        let mut buf = b"no newline".to_vec();
+
+        assert_eq!(decoder.decode(&mut buf), None);
+        assert_eq!(decoder.decode_eof(&mut buf), Some(b"no newline".to_vec()));
+        assert!(buf.is_empty());
+        // A second EOF flush on an empty buffer yields nothing further.
+        assert_eq!(decoder.decode_eof(&mut buf), None);
+    }
+
+    #[test]
+    fn length_delimited_decoder_waits_for_full_prefix() {
+        let mut decoder = LengthDelimitedDecoder;
+        let mut buf = vec![0x00, 0x00]; // Only 2 of 4 prefix bytes.
+
+        assert_eq!(decoder.decode(&mut buf), None);
+        assert_eq!(buf, vec![0x00, 0x00]);
+    }
+
+    #[test]
+    fn length_delimited_decoder_waits_for_full_payload() {
+        let mut decoder = LengthDelimitedDecoder;
+        // Prefix says 5 bytes of payload, but only 1 has arrived so far.
+        let mut buf = vec![0x00, 0x00, 0x00, 0x05, b'h'];
+
+        assert_eq!(decoder.decode(&mut buf), None);
+        assert_eq!(buf, vec![0x00, 0x00, 0x00, 0x05, b'h']);
+
+        buf.extend_from_slice(b"ello");
+        assert_eq!(decoder.decode(&mut buf), Some(b"hello".to_vec()));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn length_delimited_decoder_leaves_the_next_frame_buffered() {
+        let mut decoder = LengthDelimitedDecoder;
+        let mut buf = vec![0x00, 0x00, 0x00, 0x02, b'h', b'i', 0x00, 0x00];
+
+        assert_eq!(decoder.decode(&mut buf), Some(b"hi".to_vec()));
+        assert_eq!(buf, vec![0x00, 0x00]);
+    }
+}
@@ -1,11 +1,74 @@
 #[cfg(doctest)]
 doc_comment::doctest!("../README.md");
 
+pub mod decoder;
+#[cfg(unix)]
+mod named_pipe;
+
+#[cfg(feature = "tokio")]
+pub mod tokio_support;
+
 use std::io::{self, IsTerminal, Read};
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
+/// Default chunk size used by [`spawn_stdin_stream`], in bytes.
+pub(crate) const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Default grace window used by [`get_stdin_or_default`] to wait for the
+/// first chunk before falling back.
+const DEFAULT_FALLBACK_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A handle to a background stdin/FIFO reader thread spawned by this crate.
+///
+/// Derefs to the underlying `Receiver<Vec<u8>>`, so `recv()`/`try_recv()` work
+/// exactly as if the spawner still returned a plain receiver. In addition,
+/// `shutdown()` signals the reader thread to stop, for callers (e.g. a
+/// longer-lived service) that need to reclaim the thread without waiting for
+/// EOF. Dropping the handle signals the same cancellation automatically, so a
+/// caller that simply lets the handle go out of scope (e.g. after a
+/// `recv_timeout` gives up) does not need to call `shutdown()` explicitly.
+/// Either way the thread exits only once its in-flight read returns; a
+/// blocking read on a pipe that never sends data or closes keeps the thread
+/// alive.
+pub struct StdinHandle {
+    rx: Receiver<Vec<u8>>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl StdinHandle {
+    fn new(rx: Receiver<Vec<u8>>, cancel: Arc<AtomicBool>) -> Self {
+        Self { rx, cancel }
+    }
+
+    /// Signals the background reader thread to stop after its current chunk.
+    ///
+    /// This does not forcibly interrupt an in-flight blocking read; the
+    /// thread checks the cancellation flag between chunks.
+    pub fn shutdown(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Deref for StdinHandle {
+    type Target = Receiver<Vec<u8>>;
+
+    fn deref(&self) -> &Receiver<Vec<u8>> {
+        &self.rx
+    }
+}
+
+impl Drop for StdinHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
 /// Spawns a background thread that continuously reads from stdin as a binary stream.
 ///
 /// This function returns an `mpsc Receiver`, allowing non-blocking polling
@@ -16,6 +79,14 @@ use std::time::Duration;
 /// - This prevents blocking behavior when running interactively.
 /// - When reading from a file or pipe, the background thread captures input **as raw bytes**.
 ///
+/// Data is forwarded incrementally: each chunk read from stdin is sent as soon
+/// as it arrives, so long-running pipes (e.g. `tail -f | myapp`) produce output
+/// before the producer closes. Use [`spawn_stdin_stream_with_capacity`] to control
+/// the chunk size.
+///
+/// The receiver is returned wrapped in a [`StdinHandle`], which derefs to it
+/// and can also stop the reader thread.
+///
 /// # Returns
 /// A `Receiver<Vec<u8>>` that emits **binary data** from stdin.
 ///
@@ -41,29 +112,127 @@ use std::time::Duration;
 ///     std::thread::sleep(Duration::from_millis(500));
 /// }
 /// ```
-pub fn spawn_stdin_stream() -> Receiver<Vec<u8>> {
+pub fn spawn_stdin_stream() -> StdinHandle {
+    spawn_stdin_stream_with_capacity(DEFAULT_CHUNK_SIZE)
+}
+
+/// Like [`spawn_stdin_stream`], but lets the caller pick the read buffer size.
+///
+/// Each call to the underlying `Read::read` uses a reusable `[u8; chunk_size]`
+/// buffer, and every non-empty chunk read from stdin is sent to the returned
+/// receiver as soon as it arrives, rather than waiting for EOF. A larger
+/// `chunk_size` reduces the number of channel sends for high-throughput input;
+/// a smaller one reduces the latency of the first byte.
+///
+/// # Example
+/// ```
+/// use stdin_nonblocking::spawn_stdin_stream_with_capacity;
+///
+/// let stdin_stream = spawn_stdin_stream_with_capacity(4096);
+/// ```
+pub fn spawn_stdin_stream_with_capacity(chunk_size: usize) -> StdinHandle {
     let (tx, rx): (Sender<Vec<u8>>, Receiver<Vec<u8>>) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
 
     // If stdin is a terminal, return early (no blocking).
     if io::stdin().is_terminal() {
-        return rx;
+        return StdinHandle::new(rx, cancel);
     }
 
+    let thread_cancel = Arc::clone(&cancel);
     thread::spawn(move || {
-        let mut buffer = Vec::new();
         let stdin = io::stdin();
         let mut stdin_lock = stdin.lock();
+        read_chunks_into_channel(&mut stdin_lock, chunk_size, &tx, &thread_cancel);
+    });
+
+    StdinHandle::new(rx, cancel)
+}
 
-        match stdin_lock.read_to_end(&mut buffer) {
-            Ok(0) => (), // EOF, no data
-            Ok(_) => {
-                let _ = tx.send(buffer); // Send full binary data
+/// Reads from `reader` in `chunk_size` chunks, sending each non-empty chunk to
+/// `tx` as it arrives. Returns once `reader` hits EOF, a read fails, `tx`'s
+/// receiver is dropped, or `cancel` is set.
+///
+/// Shared by every chunked-reader spawner in this crate (stdin, named pipes, ...)
+/// so they all forward data incrementally the same way.
+fn read_chunks_into_channel<R: Read>(
+    reader: &mut R,
+    chunk_size: usize,
+    tx: &Sender<Vec<u8>>,
+    cancel: &AtomicBool,
+) {
+    let mut buffer = vec![0u8; chunk_size];
+
+    while !cancel.load(Ordering::Relaxed) {
+        match reader.read(&mut buffer) {
+            Ok(0) => break, // EOF
+            Ok(n) => {
+                if tx.send(buffer[..n].to_vec()).is_err() {
+                    break; // Receiver dropped
+                }
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                // Non-blocking source with no data ready yet (e.g. a FIFO
+                // with no data currently written); retry shortly.
+                thread::sleep(Duration::from_millis(5));
             }
-            Err(_) => (), // Read failure
+            Err(_) => break, // Read failure
         }
+    }
+}
+
+/// Spawns a background thread that continuously reads from a named pipe (FIFO)
+/// as a binary stream.
+///
+/// This mirrors [`spawn_stdin_stream`], but reads from an explicit filesystem
+/// path instead of inherited stdin: there is no `is_terminal` early-return, so
+/// the reader always starts, which makes this suitable for IPC scenarios where
+/// the data source is a well-known FIFO rather than `cmd | app`.
+///
+/// `path` is opened in non-blocking mode, and a dummy writer end is held open
+/// internally for as long as the reader runs, so a reader started before any
+/// real writer has connected never observes a false "no writer" EOF; reads
+/// simply retry until data appears. The stream only ends via
+/// [`StdinHandle::shutdown`] (or dropping the handle) — not by a writer
+/// disconnecting — since another writer may always connect later.
+///
+/// Unix-only for now: a real Windows named-pipe server needs
+/// `CreateNamedPipeW` plus overlapped I/O that doesn't fit this crate's
+/// synchronous reader loop, so that support doesn't exist yet.
+///
+/// # Errors
+/// Returns an error if `path` cannot be opened.
+///
+/// # Example
+/// ```no_run
+/// use stdin_nonblocking::spawn_named_stream;
+///
+/// let stream = spawn_named_stream("/tmp/my.fifo").expect("failed to open FIFO");
+/// while let Ok(chunk) = stream.recv() {
+///     println!("Received: {:?}", chunk);
+/// }
+/// ```
+#[cfg(unix)]
+pub fn spawn_named_stream(path: impl AsRef<Path>) -> io::Result<StdinHandle> {
+    spawn_named_stream_with_capacity(path, DEFAULT_CHUNK_SIZE)
+}
+
+/// Like [`spawn_named_stream`], but lets the caller pick the read buffer size.
+#[cfg(unix)]
+pub fn spawn_named_stream_with_capacity(
+    path: impl AsRef<Path>,
+    chunk_size: usize,
+) -> io::Result<StdinHandle> {
+    let mut source = named_pipe::open(path.as_ref())?;
+    let (tx, rx) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let thread_cancel = Arc::clone(&cancel);
+
+    thread::spawn(move || {
+        read_chunks_into_channel(&mut source, chunk_size, &tx, &thread_cancel);
     });
 
-    rx
+    Ok(StdinHandle::new(rx, cancel))
 }
 
 /// Reads stdin if available; otherwise, returns a default value.
@@ -89,13 +258,50 @@ pub fn spawn_stdin_stream() -> Receiver<Vec<u8>> {
 /// assert_eq!(input, Some(b"fallback_value".to_vec()));
 /// ```
 pub fn get_stdin_or_default(default: Option<&[u8]>) -> Option<Vec<u8>> {
+    get_stdin_or_default_timeout(default, DEFAULT_FALLBACK_TIMEOUT)
+}
+
+/// Like [`get_stdin_or_default`], but lets the caller pick the fallback grace
+/// window instead of the fixed 50ms default.
+///
+/// A pipe that stays open without sending anything (rather than one that's
+/// simply slow to produce its first chunk) falls back to `default` once
+/// `timeout` elapses, instead of blocking forever. On timeout, dropping the
+/// `StdinHandle` signals the reader thread to stop; the thread exits once its
+/// pending stdin read returns (more data or EOF), not at the timeout itself.
+///
+/// Once the first chunk arrives within `timeout`, every remaining chunk is
+/// drained (blocking, with no further timeout) until stdin reaches EOF, so
+/// input larger than one chunk is still returned in full.
+///
+/// # Example
+/// ```
+/// use stdin_nonblocking::get_stdin_or_default_timeout;
+/// use std::time::Duration;
+///
+/// let input = get_stdin_or_default_timeout(Some(b"fallback_value"), Duration::from_millis(200));
+///
+/// assert_eq!(input, Some(b"fallback_value".to_vec()));
+/// ```
+pub fn get_stdin_or_default_timeout(
+    default: Option<&[u8]>,
+    timeout: Duration,
+) -> Option<Vec<u8>> {
     if !io::stdin().is_terminal() {
         let stdin_channel = spawn_stdin_stream();
 
-        // Blocking recv() waits until data arrives or EOF occurs
-        match stdin_channel.recv() {
-            Ok(data) => return Some(data),
-            Err(e) => eprintln!("Channel closed without data: {}", e),
+        match stdin_channel.recv_timeout(timeout) {
+            Ok(first_chunk) => {
+                let mut data = first_chunk;
+                while let Ok(chunk) = stdin_channel.recv() {
+                    data.extend_from_slice(&chunk);
+                }
+                return Some(data);
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                eprintln!("Channel closed without data");
+            }
         }
     }
 
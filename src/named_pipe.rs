@@ -0,0 +1,56 @@
+//! Platform-specific helpers for opening a named pipe / FIFO path as a
+//! non-blocking [`std::io::Read`] source for [`crate::spawn_named_stream`].
+//!
+//! Only Unix FIFOs are supported today; a real Windows named-pipe server
+//! endpoint requires `CreateNamedPipeW` plus overlapped I/O plumbing that
+//! doesn't fit `std::fs::File`'s synchronous `Read`, so
+//! [`crate::spawn_named_stream`] is Unix-only until that's built out.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// A FIFO opened for non-blocking reads, plus a dummy writer held open for as
+/// long as this value lives.
+///
+/// Per POSIX, `read()` on a FIFO with *no writer currently connected* returns
+/// `Ok(0)`, indistinguishable from real EOF, even though a real writer may
+/// connect moments later. Holding our own writer end open the whole time
+/// means the FIFO always has at least one writer attached, so a "no data
+/// right now" read reports `WouldBlock` instead of a false EOF; this mirrors
+/// how Tokio's `pipe::OpenOptions::open_receiver` avoids the same ambiguity.
+pub(crate) struct Fifo {
+    reader: File,
+    _writer: File,
+}
+
+impl Read for Fifo {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Opens `path` as a non-blocking source suitable for incremental reads.
+pub(crate) fn open(path: &Path) -> io::Result<Fifo> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    // O_NONBLOCK so opening (and reading) a FIFO with no writer connected yet
+    // returns immediately instead of blocking the reader thread forever.
+    let reader = File::options()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)?;
+
+    // Opened after the reader above, so this never observes "no reader yet"
+    // (which would fail with ENXIO); it just keeps a writer attached for the
+    // lifetime of the Fifo so the reader never sees a false EOF.
+    let writer = File::options()
+        .write(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)?;
+
+    Ok(Fifo {
+        reader,
+        _writer: writer,
+    })
+}
@@ -0,0 +1,97 @@
+//! Native async equivalents of the blocking stdin helpers, built directly on
+//! `tokio::io::stdin()` instead of bridging a `std::sync::mpsc` receiver
+//! through a forwarding thread.
+
+use std::io::IsTerminal;
+use tokio::io::{self, AsyncReadExt};
+use tokio::sync::mpsc::{self, Receiver};
+
+use crate::DEFAULT_CHUNK_SIZE;
+
+/// Depth of the bounded channel returned by [`spawn_stdin_stream_async`], in
+/// buffered `Vec<u8>` messages (not bytes) — kept small and explicit so the
+/// channel's backpressure kicks in quickly instead of letting the reader get
+/// far ahead of a slow consumer.
+const CHANNEL_DEPTH: usize = 32;
+
+/// Spawns a Tokio task that continuously reads from stdin as a binary stream.
+///
+/// This is the async counterpart to [`crate::spawn_stdin_stream`]: the reader
+/// runs as a spawned task rather than an OS thread, and chunks are forwarded
+/// through a bounded `tokio::sync::mpsc::Receiver`, so a slow consumer applies
+/// backpressure to the reader instead of the thread racing ahead unbounded.
+///
+/// If stdin is a terminal (interactive mode), the returned receiver closes
+/// immediately without reading anything.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() {
+/// use stdin_nonblocking::tokio_support::spawn_stdin_stream_async;
+///
+/// let mut stdin_stream = spawn_stdin_stream_async();
+/// while let Some(chunk) = stdin_stream.recv().await {
+///     println!("Received: {:?}", chunk);
+/// }
+/// # }
+/// ```
+pub fn spawn_stdin_stream_async() -> Receiver<Vec<u8>> {
+    let (tx, rx) = mpsc::channel(CHANNEL_DEPTH);
+
+    if std::io::stdin().is_terminal() {
+        return rx;
+    }
+
+    tokio::spawn(async move {
+        let mut stdin = io::stdin();
+        let mut buffer = vec![0u8; DEFAULT_CHUNK_SIZE];
+
+        loop {
+            match stdin.read(&mut buffer).await {
+                Ok(0) => break, // EOF
+                Ok(n) => {
+                    if tx.send(buffer[..n].to_vec()).await.is_err() {
+                        break; // Receiver dropped
+                    }
+                }
+                Err(_) => break, // Read failure
+            }
+        }
+    });
+
+    rx
+}
+
+/// Reads stdin if available; otherwise, returns a default value.
+///
+/// Async counterpart to [`crate::get_stdin_or_default`], built on
+/// [`spawn_stdin_stream_async`] instead of a background thread. If stdin is a
+/// terminal, `default` is returned immediately. Otherwise there is no grace
+/// window: it waits, without a timeout, for the first chunk or EOF, and
+/// returns `default` only if stdin reaches EOF with no data. Once the first
+/// chunk arrives, every remaining chunk is drained until stdin reaches EOF, so
+/// input larger than one chunk is still returned in full.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() {
+/// use stdin_nonblocking::tokio_support::get_stdin_or_default_async;
+///
+/// let input = get_stdin_or_default_async(Some(b"fallback_value")).await;
+/// # }
+/// ```
+pub async fn get_stdin_or_default_async(default: Option<&[u8]>) -> Option<Vec<u8>> {
+    if !std::io::stdin().is_terminal() {
+        let mut stdin_stream = spawn_stdin_stream_async();
+
+        if let Some(first_chunk) = stdin_stream.recv().await {
+            let mut data = first_chunk;
+            while let Some(chunk) = stdin_stream.recv().await {
+                data.extend_from_slice(&chunk);
+            }
+            return Some(data);
+        }
+    }
+
+    default.map(|val| val.to_vec())
+}
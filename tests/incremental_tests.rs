@@ -0,0 +1,53 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+fn build_workspace() {
+    let status = Command::new("cargo")
+        .args(["build", "--workspace"])
+        .status()
+        .expect("Failed to build workspace binaries");
+
+    assert!(status.success(), "Failed to build workspace binaries");
+}
+
+/// `chunk_stream_binary` echoes each chunk it receives as soon as it arrives.
+/// If the reader still buffered to EOF (the bug this request fixed), the
+/// output below would only appear once `stdin` is dropped, and the
+/// `recv_timeout` would fire instead.
+#[test]
+fn spawn_stdin_stream_with_capacity_forwards_data_before_eof() {
+    build_workspace();
+
+    let mut child = Command::new("cargo")
+        .args(["run", "--bin", "chunk_stream_binary"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn chunk_stream_binary");
+
+    let mut stdin = child.stdin.take().expect("child has no stdin");
+    let mut stdout = child.stdout.take().expect("child has no stdout");
+
+    stdin.write_all(b"hi").expect("failed to write to child stdin");
+    stdin.flush().expect("failed to flush child stdin");
+
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = [0u8; 2];
+        if stdout.read_exact(&mut buf).is_ok() {
+            let _ = tx.send(buf.to_vec());
+        }
+    });
+
+    let received = rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("expected incremental output before stdin was closed");
+    assert_eq!(received, b"hi");
+
+    drop(stdin); // Signal EOF so the child can exit.
+    let status = child.wait().expect("child did not exit");
+    assert!(status.success());
+}
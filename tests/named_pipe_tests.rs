@@ -0,0 +1,49 @@
+#![cfg(unix)]
+
+use std::ffi::CString;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use stdin_nonblocking::spawn_named_stream;
+
+fn make_fifo(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "stdin_nonblocking_test_fifo_{}_{name}",
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+
+    let c_path = CString::new(path.to_str().expect("non-UTF8 temp path")).unwrap();
+    let result = unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) };
+    assert_eq!(result, 0, "mkfifo failed: {}", std::io::Error::last_os_error());
+
+    path
+}
+
+#[test]
+fn spawn_named_stream_reads_fifo_data() {
+    let path = make_fifo("read");
+    let stream = spawn_named_stream(&path).expect("failed to open FIFO for reading");
+
+    let writer_path = path.clone();
+    thread::spawn(move || {
+        // Give the reader a moment to start polling before a writer connects,
+        // exercising the "no writer yet" non-blocking-open path.
+        thread::sleep(Duration::from_millis(50));
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .open(&writer_path)
+            .expect("failed to open FIFO for writing");
+        file.write_all(b"hello fifo").expect("failed to write to FIFO");
+    });
+
+    let received = stream
+        .recv_timeout(Duration::from_secs(10))
+        .expect("expected data from the FIFO");
+    assert_eq!(received, b"hello fifo");
+
+    let _ = fs::remove_file(&path);
+}
@@ -101,6 +101,36 @@ fn test_text_input_handling() {
     }
 }
 
+/// Input larger than one internal read chunk must still be returned in full,
+/// not truncated to the first chunk.
+#[test]
+fn test_input_larger_than_one_chunk() {
+    // Bigger than DEFAULT_CHUNK_SIZE (8 * 1024), so a single chunk can't hold it.
+    let large_input: Vec<u8> = (0..20_000).map(|i| (i % 256) as u8).collect();
+
+    {
+        let output_bytes = run_binary("test_binary", &large_input);
+
+        assert_eq!(
+            output_bytes, large_input,
+            "Expected full input to be echoed back, but got {} bytes instead of {}",
+            output_bytes.len(),
+            large_input.len()
+        );
+    }
+
+    {
+        let output_bytes = run_binary("tokio-example-app", &large_input);
+
+        assert_eq!(
+            output_bytes, large_input,
+            "Expected full input to be echoed back, but got {} bytes instead of {}",
+            output_bytes.len(),
+            large_input.len()
+        );
+    }
+}
+
 #[test]
 fn test_empty_input() {
     {
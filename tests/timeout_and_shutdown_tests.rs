@@ -0,0 +1,89 @@
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+fn build_workspace() {
+    let status = Command::new("cargo")
+        .args(["build", "--workspace"])
+        .status()
+        .expect("Failed to build workspace binaries");
+
+    assert!(status.success(), "Failed to build workspace binaries");
+}
+
+/// A pipe that opens but never sends anything must still fall back once the
+/// configured timeout elapses, rather than blocking forever on a plain `recv()`.
+#[test]
+fn get_stdin_or_default_timeout_falls_back_when_pipe_sends_nothing() {
+    build_workspace();
+    let start = Instant::now();
+
+    let mut child = Command::new("cargo")
+        .args(["run", "--bin", "timeout_binary"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn timeout_binary");
+
+    // Keep stdin open without writing to it, so only the configured timeout
+    // (not EOF) can unblock the child.
+    let _stdin = child.stdin.take().expect("child has no stdin");
+
+    let output = child
+        .wait_with_output()
+        .expect("failed to wait for timeout_binary");
+    let elapsed = start.elapsed();
+
+    assert_eq!(output.stdout, b"fallback_value");
+    assert!(
+        elapsed >= Duration::from_millis(100),
+        "fell back before the configured 100ms timeout elapsed: {elapsed:?}"
+    );
+}
+
+#[cfg(unix)]
+mod shutdown {
+    use std::ffi::CString;
+    use std::fs;
+    use std::sync::mpsc::RecvTimeoutError;
+    use std::time::Duration;
+
+    use stdin_nonblocking::spawn_named_stream;
+
+    fn make_fifo() -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "stdin_nonblocking_test_fifo_{}_shutdown",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let c_path = CString::new(path.to_str().expect("non-UTF8 temp path")).unwrap();
+        let result = unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) };
+        assert_eq!(
+            result,
+            0,
+            "mkfifo failed: {}",
+            std::io::Error::last_os_error()
+        );
+
+        path
+    }
+
+    /// `StdinHandle::shutdown()` signals the reader thread to stop; since the
+    /// FIFO is opened non-blocking, the reader polls the cancel flag every few
+    /// milliseconds, so it should exit (dropping its `Sender`) well within the
+    /// timeout below even though no writer ever connects.
+    #[test]
+    fn shutdown_stops_the_named_pipe_reader() {
+        let path = make_fifo();
+        let stream = spawn_named_stream(&path).expect("failed to open FIFO for reading");
+
+        stream.shutdown();
+
+        match stream.recv_timeout(Duration::from_secs(10)) {
+            Err(RecvTimeoutError::Disconnected) => {}
+            other => panic!("expected the channel to disconnect after shutdown, got {other:?}"),
+        }
+
+        let _ = fs::remove_file(&path);
+    }
+}
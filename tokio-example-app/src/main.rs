@@ -1,34 +1,18 @@
 use std::io::{self, Write};
-use std::thread;
-use stdin_nonblocking::spawn_stdin_stream;
-use tokio::sync::mpsc;
+use stdin_nonblocking::tokio_support::spawn_stdin_stream_async;
 use tokio::time::{sleep, Duration};
 
-/// Maximum buffer size for async channel
-const BUFFER_SIZE: usize = 10;
 const FALLBACK_VALUE: &[u8] = b"fallback_value";
 
 #[tokio::main]
 async fn main() {
-    // Step 1: Start the blocking stdin reader
-    let blocking_stdin_stream = spawn_stdin_stream(); // std::sync::mpsc::Receiver<Vec<u8>>
+    // The reader runs as a Tokio task and forwards chunks through a bounded
+    // tokio::sync::mpsc::Receiver directly - no manual thread/channel bridge.
+    let mut stdin_stream = spawn_stdin_stream_async();
 
-    // Step 2: Create an async Tokio channel
-    let (tx, mut rx) = mpsc::channel::<Vec<u8>>(BUFFER_SIZE);
-
-    // Step 3: Spawn a thread to forward data from std::sync::mpsc to Tokio mpsc
-    thread::spawn(move || {
-        while let Ok(chunk) = blocking_stdin_stream.recv() {
-            if tx.blocking_send(chunk).is_err() {
-                break; // If the receiver is closed, stop forwarding
-            }
-        }
-    });
-
-    // Step 4: Process the async stream of binary input
     let mut received_any = false;
 
-    while let Some(chunk) = rx.recv().await {
+    while let Some(chunk) = stdin_stream.recv().await {
         received_any = true;
 
         // Simulate async work per chunk
@@ -40,7 +24,7 @@ async fn main() {
             .expect("Failed to write output");
     }
 
-    // Step 5: If no input was received, print the fallback value
+    // If no input was received, print the fallback value
     if !received_any {
         io::stdout()
             .write_all(FALLBACK_VALUE)